@@ -0,0 +1,803 @@
+//! Hand-written parse/serialize pairs specialized to the three benchmark
+//! corpora, establishing the throughput ceiling the other libraries are
+//! measured against. `canada.json` gets a full hand-rolled recursive-descent
+//! parser and serializer, skipping both the generic DOM representation and
+//! Serde's visitor machinery entirely. `citm_catalog.json` and
+//! `twitter.json` are too deeply nested to hand-roll a parser for
+//! profitably, so parsing still goes through `serde_json`; their stringify
+//! side runs through the hand-written [`CompactSerializer`] instead, so the
+//! escape-scan comparison isn't just re-measuring `serde_json` against
+//! itself.
+
+use std::io::{self, Write};
+
+use json_benchmark::canada::{Canada, Feature, Geometry, Properties};
+use json_benchmark::citm_catalog::CitmCatalog;
+use json_benchmark::twitter::Twitter;
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+/// Number of UTF-8 bytes making up the codepoint that starts with `lead`.
+fn utf8_char_len(lead: u8) -> usize {
+    match lead {
+        0x00..=0x7f => 1,
+        0xc0..=0xdf => 2,
+        0xe0..=0xef => 3,
+        0xf0..=0xf7 => 4,
+        _ => panic!("invalid UTF-8 leading byte {lead:#x}"),
+    }
+}
+
+impl<'a> Reader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Reader { bytes, pos: 0 }
+    }
+
+    fn skip_ws(&mut self) {
+        while let Some(&b) = self.bytes.get(self.pos) {
+            if b == b' ' || b == b'\t' || b == b'\n' || b == b'\r' {
+                self.pos += 1;
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn expect(&mut self, byte: u8) {
+        self.skip_ws();
+        assert_eq!(self.bytes[self.pos], byte);
+        self.pos += 1;
+    }
+
+    fn peek_after_ws(&mut self) -> u8 {
+        self.skip_ws();
+        self.bytes[self.pos]
+    }
+
+    fn parse_string(&mut self) -> String {
+        self.expect(b'"');
+        let mut s = String::new();
+        loop {
+            let b = self.bytes[self.pos];
+            match b {
+                b'"' => {
+                    self.pos += 1;
+                    break;
+                }
+                b'\\' => {
+                    self.pos += 1;
+                    let escape = self.bytes[self.pos];
+                    self.pos += 1;
+                    match escape {
+                        b'"' => s.push('"'),
+                        b'\\' => s.push('\\'),
+                        b'/' => s.push('/'),
+                        b'n' => s.push('\n'),
+                        b't' => s.push('\t'),
+                        b'r' => s.push('\r'),
+                        b'b' => s.push('\u{8}'),
+                        b'f' => s.push('\u{c}'),
+                        b'u' => {
+                            let hex = std::str::from_utf8(&self.bytes[self.pos..self.pos + 4]).unwrap();
+                            let code = u32::from_str_radix(hex, 16).unwrap();
+                            self.pos += 4;
+                            s.push(char::from_u32(code).unwrap());
+                        }
+                        _ => panic!("invalid escape"),
+                    }
+                }
+                // Any other byte is part of a raw (possibly multi-byte UTF-8)
+                // run; copy it verbatim instead of reinterpreting each byte
+                // as its own Latin-1 codepoint.
+                _ => {
+                    let start = self.pos;
+                    let len = utf8_char_len(b);
+                    self.pos += len;
+                    s.push_str(std::str::from_utf8(&self.bytes[start..self.pos]).unwrap());
+                }
+            }
+        }
+        s
+    }
+
+    fn parse_number(&mut self) -> f64 {
+        self.skip_ws();
+        let start = self.pos;
+        if self.bytes[self.pos] == b'-' {
+            self.pos += 1;
+        }
+        while matches!(self.bytes.get(self.pos), Some(b'0'..=b'9' | b'.' | b'e' | b'E' | b'+' | b'-')) {
+            self.pos += 1;
+        }
+        std::str::from_utf8(&self.bytes[start..self.pos])
+            .unwrap()
+            .parse()
+            .unwrap()
+    }
+
+    fn parse_array<T>(&mut self, mut parse_elem: impl FnMut(&mut Self) -> T) -> Vec<T> {
+        self.expect(b'[');
+        let mut out = Vec::new();
+        if self.peek_after_ws() == b']' {
+            self.pos += 1;
+            return out;
+        }
+        loop {
+            out.push(parse_elem(self));
+            self.skip_ws();
+            match self.bytes[self.pos] {
+                b',' => {
+                    self.pos += 1;
+                }
+                b']' => {
+                    self.pos += 1;
+                    break;
+                }
+                _ => panic!("expected , or ]"),
+            }
+        }
+        out
+    }
+}
+
+/// Write a string assuming none of its bytes require JSON escaping.
+fn write_str_noescape(writer: &mut dyn Write, s: &str) -> io::Result<()> {
+    writer.write_all(b"\"")?;
+    writer.write_all(s.as_bytes())?;
+    writer.write_all(b"\"")
+}
+
+/// Write a string after scanning every byte for characters that need
+/// escaping, matching what a general-purpose serializer has to do.
+fn write_str_escaped(writer: &mut dyn Write, s: &str) -> io::Result<()> {
+    writer.write_all(b"\"")?;
+    let mut start = 0;
+    for (i, b) in s.bytes().enumerate() {
+        if !matches!(b, b'"' | b'\\' | 0x00..=0x1f) {
+            continue;
+        }
+        writer.write_all(&s.as_bytes()[start..i])?;
+        match b {
+            b'"' => writer.write_all(b"\\\"")?,
+            b'\\' => writer.write_all(b"\\\\")?,
+            b'\n' => writer.write_all(b"\\n")?,
+            b'\t' => writer.write_all(b"\\t")?,
+            b'\r' => writer.write_all(b"\\r")?,
+            0x08 => writer.write_all(b"\\b")?,
+            0x0c => writer.write_all(b"\\f")?,
+            _ => write!(writer, "\\u{b:04x}")?,
+        }
+        start = i + 1;
+    }
+    writer.write_all(&s.as_bytes()[start..])?;
+    writer.write_all(b"\"")
+}
+
+fn write_coordinate(writer: &mut impl Write, (x, y): &(f64, f64)) -> io::Result<()> {
+    write!(writer, "[{},{}]", x, y)
+}
+
+pub fn parse_canada(bytes: &[u8]) -> Canada {
+    let mut reader = Reader::new(bytes);
+    reader.expect(b'{');
+    let mut ty = String::new();
+    let mut features = Vec::new();
+    loop {
+        let key = reader.parse_string();
+        reader.expect(b':');
+        match key.as_str() {
+            "type" => ty = reader.parse_string(),
+            "features" => {
+                features = reader.parse_array(|r| {
+                    r.expect(b'{');
+                    let mut feature_ty = String::new();
+                    let mut properties = Properties { name: String::new() };
+                    let mut geometry = Geometry {
+                        ty: String::new(),
+                        coordinates: Vec::new(),
+                    };
+                    loop {
+                        let key = r.parse_string();
+                        r.expect(b':');
+                        match key.as_str() {
+                            "type" => feature_ty = r.parse_string(),
+                            "properties" => {
+                                r.expect(b'{');
+                                r.parse_string(); // "name"
+                                r.expect(b':');
+                                properties.name = r.parse_string();
+                                r.expect(b'}');
+                            }
+                            "geometry" => {
+                                r.expect(b'{');
+                                loop {
+                                    let key = r.parse_string();
+                                    r.expect(b':');
+                                    match key.as_str() {
+                                        "type" => geometry.ty = r.parse_string(),
+                                        "coordinates" => {
+                                            geometry.coordinates = r.parse_array(|r| {
+                                                r.parse_array(|r| {
+                                                    r.expect(b'[');
+                                                    let x = r.parse_number();
+                                                    r.expect(b',');
+                                                    let y = r.parse_number();
+                                                    r.expect(b']');
+                                                    (x, y)
+                                                })
+                                            });
+                                        }
+                                        _ => panic!("unknown geometry key {key}"),
+                                    }
+                                    r.skip_ws();
+                                    match r.bytes[r.pos] {
+                                        b',' => r.pos += 1,
+                                        b'}' => {
+                                            r.pos += 1;
+                                            break;
+                                        }
+                                        _ => panic!("expected , or }}"),
+                                    }
+                                }
+                            }
+                            _ => panic!("unknown feature key {key}"),
+                        }
+                        r.skip_ws();
+                        match r.bytes[r.pos] {
+                            b',' => r.pos += 1,
+                            b'}' => {
+                                r.pos += 1;
+                                break;
+                            }
+                            _ => panic!("expected , or }}"),
+                        }
+                    }
+                    Feature {
+                        ty: feature_ty,
+                        properties,
+                        geometry,
+                    }
+                });
+            }
+            _ => panic!("unknown canada key {key}"),
+        }
+        reader.skip_ws();
+        match reader.bytes[reader.pos] {
+            b',' => reader.pos += 1,
+            b'}' => {
+                reader.pos += 1;
+                break;
+            }
+            _ => panic!("expected , or }}"),
+        }
+    }
+    Canada { ty, features }
+}
+
+fn stringify_canada(
+    writer: &mut impl Write,
+    canada: &Canada,
+    write_str: fn(&mut dyn Write, &str) -> io::Result<()>,
+) -> io::Result<()> {
+    write!(writer, "{{\"type\":")?;
+    write_str(writer, &canada.ty)?;
+    write!(writer, ",\"features\":[")?;
+    for (i, feature) in canada.features.iter().enumerate() {
+        if i > 0 {
+            writer.write_all(b",")?;
+        }
+        write!(writer, "{{\"type\":")?;
+        write_str(writer, &feature.ty)?;
+        write!(writer, ",\"properties\":{{\"name\":")?;
+        write_str(writer, &feature.properties.name)?;
+        write!(writer, "}},\"geometry\":{{\"type\":")?;
+        write_str(writer, &feature.geometry.ty)?;
+        write!(writer, ",\"coordinates\":[")?;
+        for (j, ring) in feature.geometry.coordinates.iter().enumerate() {
+            if j > 0 {
+                writer.write_all(b",")?;
+            }
+            writer.write_all(b"[")?;
+            for (k, coordinate) in ring.iter().enumerate() {
+                if k > 0 {
+                    writer.write_all(b",")?;
+                }
+                write_coordinate(writer, coordinate)?;
+            }
+            writer.write_all(b"]")?;
+        }
+        write!(writer, "]}}}}")?;
+    }
+    write!(writer, "]}}")
+}
+
+pub fn stringify_canada_noescape(writer: &mut impl Write, canada: &Canada) -> io::Result<()> {
+    stringify_canada(writer, canada, |w, s| write_str_noescape(w, s))
+}
+
+pub fn stringify_canada_escaped(writer: &mut impl Write, canada: &Canada) -> io::Result<()> {
+    stringify_canada(writer, canada, |w, s| write_str_escaped(w, s))
+}
+
+/// `citm_catalog.json` and `twitter.json` have too many optional/nested
+/// fields to hand-roll a recursive-descent parser profitably, so parsing
+/// still goes through `serde_json`'s visitor machinery for those two
+/// corpora. Stringifying is the side the escape-scan comparison actually
+/// cares about, so it runs through [`CompactSerializer`] below instead of
+/// `serde_json::to_writer`, with `write_str_noescape`/`write_str_escaped`
+/// plugged in as the only difference between the two variants.
+pub fn parse_citm_catalog(bytes: &[u8]) -> CitmCatalog {
+    serde_json::from_slice(bytes).unwrap()
+}
+
+pub fn stringify_citm_catalog_noescape(writer: &mut impl Write, value: &CitmCatalog) -> io::Result<()> {
+    CompactSerializer::write(writer, value, write_str_noescape)
+}
+
+pub fn stringify_citm_catalog_escaped(writer: &mut impl Write, value: &CitmCatalog) -> io::Result<()> {
+    CompactSerializer::write(writer, value, write_str_escaped)
+}
+
+pub fn parse_twitter(bytes: &[u8]) -> Twitter {
+    serde_json::from_slice(bytes).unwrap()
+}
+
+pub fn stringify_twitter_noescape(writer: &mut impl Write, value: &Twitter) -> io::Result<()> {
+    CompactSerializer::write(writer, value, write_str_noescape)
+}
+
+pub fn stringify_twitter_escaped(writer: &mut impl Write, value: &Twitter) -> io::Result<()> {
+    CompactSerializer::write(writer, value, write_str_escaped)
+}
+
+/// A hand-written compact-JSON `serde::Serializer`, parameterized over the
+/// string-writing strategy so it can serve as both the "noescape" and
+/// "escaped" stringify benchmark without going through `serde_json` at all.
+struct CompactSerializer<'w, W: Write + ?Sized> {
+    writer: &'w mut W,
+    write_str: fn(&mut dyn Write, &str) -> io::Result<()>,
+}
+
+impl<'w, W: Write + ?Sized> CompactSerializer<'w, W> {
+    fn write<T: serde::Serialize + ?Sized>(
+        writer: &mut W,
+        value: &T,
+        write_str: fn(&mut dyn Write, &str) -> io::Result<()>,
+    ) -> io::Result<()> {
+        value
+            .serialize(CompactSerializer { writer, write_str })
+            .map_err(|SerError(e)| e)
+    }
+}
+
+/// Wraps the `io::Error`s that can occur while writing, so `CompactSerializer`
+/// has something to use as `serde::Serializer::Error`.
+struct SerError(io::Error);
+
+impl std::fmt::Debug for SerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl std::fmt::Display for SerError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        std::fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl std::error::Error for SerError {}
+
+impl serde::ser::Error for SerError {
+    fn custom<T: std::fmt::Display>(msg: T) -> Self {
+        SerError(io::Error::new(io::ErrorKind::Other, msg.to_string()))
+    }
+}
+
+impl From<io::Error> for SerError {
+    fn from(e: io::Error) -> Self {
+        SerError(e)
+    }
+}
+
+macro_rules! serialize_display {
+    ($($method:ident($ty:ty)),* $(,)?) => {
+        $(
+            fn $method(self, v: $ty) -> Result<(), SerError> {
+                write!(self.writer, "{v}")?;
+                Ok(())
+            }
+        )*
+    };
+}
+
+impl<'w, W: Write + ?Sized> serde::Serializer for CompactSerializer<'w, W> {
+    type Ok = ();
+    type Error = SerError;
+    type SerializeSeq = Compound<'w, W>;
+    type SerializeTuple = Compound<'w, W>;
+    type SerializeTupleStruct = Compound<'w, W>;
+    type SerializeTupleVariant = Compound<'w, W>;
+    type SerializeMap = Compound<'w, W>;
+    type SerializeStruct = Compound<'w, W>;
+    type SerializeStructVariant = Compound<'w, W>;
+
+    serialize_display!(
+        serialize_bool(bool),
+        serialize_i8(i8),
+        serialize_i16(i16),
+        serialize_i32(i32),
+        serialize_i64(i64),
+        serialize_u8(u8),
+        serialize_u16(u16),
+        serialize_u32(u32),
+        serialize_u64(u64),
+        serialize_f32(f32),
+        serialize_f64(f64),
+    );
+
+    fn serialize_char(self, v: char) -> Result<(), SerError> {
+        let mut buf = [0u8; 4];
+        self.serialize_str(v.encode_utf8(&mut buf))
+    }
+
+    fn serialize_str(self, v: &str) -> Result<(), SerError> {
+        (self.write_str)(self.writer, v)?;
+        Ok(())
+    }
+
+    fn serialize_bytes(self, v: &[u8]) -> Result<(), SerError> {
+        self.serialize_seq(Some(v.len()))
+            .and_then(|mut seq| {
+                use serde::ser::SerializeSeq;
+                for byte in v {
+                    seq.serialize_element(byte)?;
+                }
+                seq.end()
+            })
+    }
+
+    fn serialize_none(self) -> Result<(), SerError> {
+        self.writer.write_all(b"null")?;
+        Ok(())
+    }
+
+    fn serialize_some<T: serde::Serialize + ?Sized>(self, value: &T) -> Result<(), SerError> {
+        value.serialize(self)
+    }
+
+    fn serialize_unit(self) -> Result<(), SerError> {
+        self.writer.write_all(b"null")?;
+        Ok(())
+    }
+
+    fn serialize_unit_struct(self, _name: &'static str) -> Result<(), SerError> {
+        self.serialize_unit()
+    }
+
+    fn serialize_unit_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+    ) -> Result<(), SerError> {
+        self.serialize_str(variant)
+    }
+
+    fn serialize_newtype_struct<T: serde::Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        value: &T,
+    ) -> Result<(), SerError> {
+        value.serialize(self)
+    }
+
+    fn serialize_newtype_variant<T: serde::Serialize + ?Sized>(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        value: &T,
+    ) -> Result<(), SerError> {
+        write!(self.writer, "{{\"{variant}\":")?;
+        value.serialize(CompactSerializer {
+            writer: self.writer,
+            write_str: self.write_str,
+        })?;
+        self.writer.write_all(b"}")?;
+        Ok(())
+    }
+
+    fn serialize_seq(self, _len: Option<usize>) -> Result<Compound<'w, W>, SerError> {
+        self.writer.write_all(b"[")?;
+        Ok(Compound::new(self))
+    }
+
+    fn serialize_tuple(self, len: Option<usize>) -> Result<Compound<'w, W>, SerError> {
+        self.serialize_seq(len)
+    }
+
+    fn serialize_tuple_struct(
+        self,
+        _name: &'static str,
+        len: usize,
+    ) -> Result<Compound<'w, W>, SerError> {
+        self.serialize_seq(Some(len))
+    }
+
+    fn serialize_tuple_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'w, W>, SerError> {
+        write!(self.writer, "{{\"{variant}\":[")?;
+        Ok(Compound::new_closing(self, b"}"))
+    }
+
+    fn serialize_map(self, _len: Option<usize>) -> Result<Compound<'w, W>, SerError> {
+        self.writer.write_all(b"{")?;
+        Ok(Compound::new(self))
+    }
+
+    fn serialize_struct(
+        self,
+        _name: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'w, W>, SerError> {
+        self.writer.write_all(b"{")?;
+        Ok(Compound::new(self))
+    }
+
+    fn serialize_struct_variant(
+        self,
+        _name: &'static str,
+        _variant_index: u32,
+        variant: &'static str,
+        _len: usize,
+    ) -> Result<Compound<'w, W>, SerError> {
+        write!(self.writer, "{{\"{variant}\":{{")?;
+        Ok(Compound::new_closing(self, b"}"))
+    }
+}
+
+/// Shared state for every multi-element `serde::Serializer` compound type
+/// (seq/tuple/map/struct/...): the underlying writer, whether a comma is due
+/// before the next element, and the closing bytes to emit on `end`.
+struct Compound<'w, W: Write + ?Sized> {
+    writer: &'w mut W,
+    write_str: fn(&mut dyn Write, &str) -> io::Result<()>,
+    wrote_element: bool,
+    closing: &'static [u8],
+}
+
+impl<'w, W: Write + ?Sized> Compound<'w, W> {
+    fn new(ser: CompactSerializer<'w, W>) -> Self {
+        Compound::new_closing(ser, b"")
+    }
+
+    fn new_closing(ser: CompactSerializer<'w, W>, closing: &'static [u8]) -> Self {
+        Compound {
+            writer: ser.writer,
+            write_str: ser.write_str,
+            wrote_element: false,
+            closing,
+        }
+    }
+
+    fn element_serializer(&mut self) -> io::Result<CompactSerializer<'_, W>> {
+        if self.wrote_element {
+            self.writer.write_all(b",")?;
+        }
+        self.wrote_element = true;
+        Ok(CompactSerializer {
+            writer: self.writer,
+            write_str: self.write_str,
+        })
+    }
+}
+
+impl<'w, W: Write + ?Sized> serde::ser::SerializeSeq for Compound<'w, W> {
+    type Ok = ();
+    type Error = SerError;
+
+    fn serialize_element<T: serde::Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerError> {
+        let ser = self.element_serializer()?;
+        value.serialize(ser)
+    }
+
+    fn end(self) -> Result<(), SerError> {
+        self.writer.write_all(b"]")?;
+        self.writer.write_all(self.closing)?;
+        Ok(())
+    }
+}
+
+impl<'w, W: Write + ?Sized> serde::ser::SerializeTuple for Compound<'w, W> {
+    type Ok = ();
+    type Error = SerError;
+
+    fn serialize_element<T: serde::Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerError> {
+        serde::ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<(), SerError> {
+        serde::ser::SerializeSeq::end(self)
+    }
+}
+
+impl<'w, W: Write + ?Sized> serde::ser::SerializeTupleStruct for Compound<'w, W> {
+    type Ok = ();
+    type Error = SerError;
+
+    fn serialize_field<T: serde::Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerError> {
+        serde::ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<(), SerError> {
+        serde::ser::SerializeSeq::end(self)
+    }
+}
+
+impl<'w, W: Write + ?Sized> serde::ser::SerializeTupleVariant for Compound<'w, W> {
+    type Ok = ();
+    type Error = SerError;
+
+    fn serialize_field<T: serde::Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerError> {
+        serde::ser::SerializeSeq::serialize_element(self, value)
+    }
+
+    fn end(self) -> Result<(), SerError> {
+        serde::ser::SerializeSeq::end(self)
+    }
+}
+
+impl<'w, W: Write + ?Sized> serde::ser::SerializeMap for Compound<'w, W> {
+    type Ok = ();
+    type Error = SerError;
+
+    fn serialize_key<T: serde::Serialize + ?Sized>(&mut self, key: &T) -> Result<(), SerError> {
+        let ser = self.element_serializer()?;
+        key.serialize(ser)
+    }
+
+    fn serialize_value<T: serde::Serialize + ?Sized>(&mut self, value: &T) -> Result<(), SerError> {
+        self.writer.write_all(b":")?;
+        let ser = CompactSerializer {
+            writer: self.writer,
+            write_str: self.write_str,
+        };
+        value.serialize(ser)
+    }
+
+    fn end(self) -> Result<(), SerError> {
+        self.writer.write_all(b"}")?;
+        self.writer.write_all(self.closing)?;
+        Ok(())
+    }
+}
+
+impl<'w, W: Write + ?Sized> serde::ser::SerializeStruct for Compound<'w, W> {
+    type Ok = ();
+    type Error = SerError;
+
+    fn serialize_field<T: serde::Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SerError> {
+        if self.wrote_element {
+            self.writer.write_all(b",")?;
+        }
+        self.wrote_element = true;
+        (self.write_str)(self.writer, key)?;
+        self.writer.write_all(b":")?;
+        let ser = CompactSerializer {
+            writer: self.writer,
+            write_str: self.write_str,
+        };
+        value.serialize(ser)
+    }
+
+    fn end(self) -> Result<(), SerError> {
+        self.writer.write_all(b"}")?;
+        self.writer.write_all(self.closing)?;
+        Ok(())
+    }
+}
+
+impl<'w, W: Write + ?Sized> serde::ser::SerializeStructVariant for Compound<'w, W> {
+    type Ok = ();
+    type Error = SerError;
+
+    fn serialize_field<T: serde::Serialize + ?Sized>(
+        &mut self,
+        key: &'static str,
+        value: &T,
+    ) -> Result<(), SerError> {
+        serde::ser::SerializeStruct::serialize_field(self, key, value)
+    }
+
+    fn end(self) -> Result<(), SerError> {
+        serde::ser::SerializeStruct::end(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample_canada() -> Canada {
+        Canada {
+            ty: "caf\u{e9}".to_string(),
+            features: vec![Feature {
+                ty: "Feature".to_string(),
+                properties: Properties {
+                    name: "t\u{e9}st \"quoted\"\n".to_string(),
+                },
+                geometry: Geometry {
+                    ty: "Polygon".to_string(),
+                    coordinates: vec![vec![(1.0, 2.0), (3.0, 4.0)]],
+                },
+            }],
+        }
+    }
+
+    #[test]
+    fn parse_string_passes_through_raw_multi_byte_utf8() {
+        let mut reader = Reader::new("\"caf\u{e9} \u{65e5}\u{672c}\"".as_bytes());
+        assert_eq!(reader.parse_string(), "caf\u{e9} \u{65e5}\u{672c}");
+    }
+
+    #[test]
+    fn parse_string_decodes_unicode_escapes() {
+        let mut reader = Reader::new(b"\"caf\\u00e9\"");
+        assert_eq!(reader.parse_string(), "caf\u{e9}");
+    }
+
+    #[test]
+    fn write_str_escaped_covers_the_full_control_range() {
+        let mut buf = Vec::new();
+        write_str_escaped(&mut buf, "\u{8}\u{c}\u{1}\n\t\r\"\\").unwrap();
+        assert_eq!(
+            std::str::from_utf8(&buf).unwrap(),
+            r#""\b\f\n\t\r\"\\""#
+        );
+    }
+
+    #[test]
+    fn write_str_noescape_writes_bytes_verbatim() {
+        let mut buf = Vec::new();
+        write_str_noescape(&mut buf, "caf\u{e9}").unwrap();
+        assert_eq!(std::str::from_utf8(&buf).unwrap(), "\"caf\u{e9}\"");
+    }
+
+    #[test]
+    fn canada_round_trips_through_escaped_stringify_and_parse() {
+        let canada = sample_canada();
+        let mut buf = Vec::new();
+        stringify_canada_escaped(&mut buf, &canada).unwrap();
+
+        let parsed = parse_canada(&buf);
+        assert_eq!(parsed.ty, canada.ty);
+        assert_eq!(parsed.features[0].ty, canada.features[0].ty);
+        assert_eq!(parsed.features[0].properties.name, canada.features[0].properties.name);
+        assert_eq!(parsed.features[0].geometry.ty, canada.features[0].geometry.ty);
+        assert_eq!(parsed.features[0].geometry.coordinates, canada.features[0].geometry.coordinates);
+    }
+
+    #[test]
+    fn canada_escaped_stringify_produces_valid_json() {
+        let canada = sample_canada();
+        let mut buf = Vec::new();
+        stringify_canada_escaped(&mut buf, &canada).unwrap();
+
+        let value: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+        assert_eq!(value["type"], "caf\u{e9}");
+        assert_eq!(value["features"][0]["properties"]["name"], "t\u{e9}st \"quoted\"\n");
+    }
+}
@@ -0,0 +1,124 @@
+use std::time::{Duration, Instant};
+
+pub struct Benchmark {
+    durations: Vec<Duration>,
+}
+
+impl Benchmark {
+    pub fn new() -> Self {
+        Benchmark {
+            durations: Vec::new(),
+        }
+    }
+
+    pub fn start(&mut self) -> Timer<'_> {
+        Timer {
+            benchmark: self,
+            start: Instant::now(),
+        }
+    }
+
+    pub fn min_elapsed(&self) -> Duration {
+        *self.durations.iter().min().unwrap()
+    }
+
+    /// Median, mean, standard deviation, and 99th percentile across every
+    /// trial. `min_elapsed` alone hides warmup noise and tail latency; this
+    /// is what lets two runs on different machines be compared for more
+    /// than just their best case.
+    #[cfg(feature = "stats")]
+    pub fn stats(&self) -> Stats {
+        let mut sorted = self.durations.clone();
+        sorted.sort();
+        let n = sorted.len();
+
+        let mean_secs = sorted.iter().map(Duration::as_secs_f64).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|duration| {
+                let diff = duration.as_secs_f64() - mean_secs;
+                diff * diff
+            })
+            .sum::<f64>()
+            / n as f64;
+
+        Stats {
+            median: sorted[n / 2],
+            mean: Duration::from_secs_f64(mean_secs),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+            p99: sorted[(((n as f64) * 0.99) as usize).min(n - 1)],
+        }
+    }
+}
+
+pub struct Timer<'a> {
+    benchmark: &'a mut Benchmark,
+    start: Instant,
+}
+
+impl<'a> Timer<'a> {
+    pub fn stop(self) {
+        let elapsed = self.start.elapsed();
+        self.benchmark.durations.push(elapsed);
+    }
+}
+
+#[cfg(feature = "stats")]
+pub struct Stats {
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+    pub p99: Duration,
+}
+
+pub fn bench<T>(num_trials: usize, mut f: impl FnMut() -> T) -> Duration {
+    let mut benchmark = Benchmark::new();
+    for _ in 0..num_trials {
+        let timer = benchmark.start();
+        let value = f();
+        timer.stop();
+        drop(value);
+    }
+    benchmark.min_elapsed()
+}
+
+pub fn bench_with_buf(num_trials: usize, cap: usize, mut f: impl FnMut(&mut Vec<u8>)) -> Duration {
+    let mut benchmark = Benchmark::new();
+    let mut buf = Vec::with_capacity(cap);
+    for _ in 0..num_trials {
+        buf.clear();
+        let timer = benchmark.start();
+        f(&mut buf);
+        timer.stop();
+    }
+    benchmark.min_elapsed()
+}
+
+/// Like `bench`, but keeps every trial's duration around so the caller can
+/// report the full distribution instead of just the minimum.
+#[cfg(feature = "stats")]
+pub fn bench_stats<T>(num_trials: usize, mut f: impl FnMut() -> T) -> Stats {
+    let mut benchmark = Benchmark::new();
+    for _ in 0..num_trials {
+        let timer = benchmark.start();
+        let value = f();
+        timer.stop();
+        drop(value);
+    }
+    benchmark.stats()
+}
+
+/// Like `bench_with_buf`, but keeps every trial's duration around so the
+/// caller can report the full distribution instead of just the minimum.
+#[cfg(feature = "stats")]
+pub fn bench_stats_with_buf(num_trials: usize, cap: usize, mut f: impl FnMut(&mut Vec<u8>)) -> Stats {
+    let mut benchmark = Benchmark::new();
+    let mut buf = Vec::with_capacity(cap);
+    for _ in 0..num_trials {
+        buf.clear();
+        let timer = benchmark.start();
+        f(&mut buf);
+        timer.stop();
+    }
+    benchmark.stats()
+}
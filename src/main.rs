@@ -1,12 +1,207 @@
 #![allow(clippy::needless_borrow, clippy::wildcard_imports)]
 
+use std::alloc::{GlobalAlloc, Layout};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Wraps jemalloc's allocator with a running byte counter and a high-water
+/// mark, so [`measure_peak_allocated`] can capture the true peak bytes live
+/// at any point during a call, not just what's still resident once it
+/// returns. `CURRENT_ALLOCATED`/`PEAK_ALLOCATED` are process-global, so this
+/// is only meaningful while benchmarks run single-threaded.
+struct TrackingAllocator {
+    inner: jemallocator::Jemalloc,
+}
+
+static CURRENT_ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static PEAK_ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+fn track_alloc(size: usize) {
+    let current = CURRENT_ALLOCATED.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK_ALLOCATED.fetch_max(current, Ordering::Relaxed);
+}
+
+fn track_dealloc(size: usize) {
+    CURRENT_ALLOCATED.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc(layout);
+        if !ptr.is_null() {
+            track_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.inner.dealloc(ptr, layout);
+        track_dealloc(layout.size());
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = self.inner.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            track_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = self.inner.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            track_dealloc(layout.size());
+            track_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
 #[global_allocator]
-static ALLOC: jemallocator::Jemalloc = jemallocator::Jemalloc;
+static ALLOC: TrackingAllocator = TrackingAllocator {
+    inner: jemallocator::Jemalloc,
+};
 
 use json_benchmark::*;
 
+mod baseline;
+mod bench_timer;
+
+use std::cell::RefCell;
 use std::fs::File;
 use std::io::{self, Read, Write};
+use std::sync::OnceLock;
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum OutputFormat {
+    Table,
+    Json,
+    Csv,
+}
+
+fn parse_output_format() -> OutputFormat {
+    let flag = std::env::args().find_map(|arg| arg.strip_prefix("--format=").map(str::to_owned));
+    match flag.or_else(|| std::env::var("JSON_BENCHMARK_FORMAT").ok()).as_deref() {
+        None | Some("table") => OutputFormat::Table,
+        Some("json") => OutputFormat::Json,
+        Some("csv") => OutputFormat::Csv,
+        Some(other) => panic!("unknown --format: {other} (expected json, csv, or table)"),
+    }
+}
+
+fn output_format() -> OutputFormat {
+    static FORMAT: OnceLock<OutputFormat> = OnceLock::new();
+    *FORMAT.get_or_init(parse_output_format)
+}
+
+#[derive(serde::Serialize)]
+struct Measurement {
+    library: &'static str,
+    file: &'static str,
+    mode: &'static str,
+    unit: &'static str,
+    value: f64,
+}
+
+thread_local! {
+    static RESULTS: RefCell<Vec<Measurement>> = RefCell::new(Vec::new());
+}
+
+/// Prints an aligned column in table mode; otherwise records the measurement
+/// so it can be emitted as structured JSON/CSV once every library has run.
+fn report(library: &'static str, file: &'static str, mode: &'static str, unit: &'static str, value: f64) {
+    match output_format() {
+        OutputFormat::Table => {
+            print!("{:6} {}", value, unit);
+            io::stdout().flush().unwrap();
+        }
+        OutputFormat::Json | OutputFormat::Csv => {
+            RESULTS.with(|results| {
+                results.borrow_mut().push(Measurement {
+                    library,
+                    file,
+                    mode,
+                    unit,
+                    value,
+                })
+            });
+        }
+    }
+}
+
+fn record(library: &'static str, file: &'static str, mode: &'static str, mb_per_s: f64) {
+    report(library, file, mode, "MB/s", mb_per_s);
+}
+
+#[cfg(feature = "alloc-stats")]
+fn record_alloc(library: &'static str, file: &'static str, mode: &'static str, bytes_per_input_byte: f64) {
+    report(library, file, mode, "B/input-B", bytes_per_input_byte);
+}
+
+fn print_results() {
+    match output_format() {
+        OutputFormat::Table => {}
+        OutputFormat::Json => {
+            RESULTS.with(|results| {
+                serde_json::to_writer_pretty(io::stdout(), &*results.borrow()).unwrap();
+            });
+            println!();
+        }
+        OutputFormat::Csv => {
+            println!("library,file,mode,unit,value");
+            RESULTS.with(|results| {
+                for measurement in results.borrow().iter() {
+                    println!(
+                        "{},{},{},{},{}",
+                        measurement.library,
+                        measurement.file,
+                        measurement.mode,
+                        measurement.unit,
+                        measurement.value
+                    );
+                }
+            });
+        }
+    }
+}
+
+/// Resets `TrackingAllocator`'s high-water mark to the current byte count,
+/// runs `f` once and reads the mark back afterwards, returning `f`'s result along with the true peak bytes live
+/// above the pre-call baseline at any point during the call — including
+/// transient allocate-then-free churn (e.g. a `Vec` reallocating and
+/// dropping its smaller old buffer) that a simple before/after delta of
+/// bytes still resident at the end would miss.
+#[cfg(feature = "alloc-stats")]
+fn measure_peak_allocated<T>(f: impl FnOnce() -> T) -> (T, u64) {
+    let before = CURRENT_ALLOCATED.load(Ordering::Relaxed);
+    PEAK_ALLOCATED.store(before, Ordering::Relaxed);
+    let value = f();
+    let peak = PEAK_ALLOCATED.load(Ordering::Relaxed);
+    (value, peak.saturating_sub(before) as u64)
+}
+
+/// Like `print!`, but silent outside table mode so the human-readable
+/// layout doesn't get interleaved with `--format=json`/`--format=csv` output.
+macro_rules! tprint {
+    ($($arg:tt)*) => {
+        if output_format() == OutputFormat::Table {
+            print!($($arg)*);
+            io::stdout().flush().unwrap();
+        }
+    };
+}
+
+macro_rules! tprintln {
+    () => {
+        if output_format() == OutputFormat::Table {
+            println!();
+        }
+    };
+    ($($arg:tt)*) => {
+        if output_format() == OutputFormat::Table {
+            println!($($arg)*);
+        }
+    };
+}
 
 macro_rules! bench {
     {
@@ -15,10 +210,11 @@ macro_rules! bench {
         $($args:tt)*
     } => {
         let name = format!(" {} ", $name);
-        println!("\n{:=^26} parse|stringify ===== parse|stringify ====", name);
+        tprintln!("\n{:=^26} parse|stringify ===== parse|stringify ====", name);
 
         #[cfg(feature = "file-canada")]
         $bench! {
+            library: $name,
             path: "data/canada.json",
             structure: canada::Canada,
             $($args)*
@@ -26,6 +222,7 @@ macro_rules! bench {
 
         #[cfg(feature = "file-citm-catalog")]
         $bench! {
+            library: $name,
             path: "data/citm_catalog.json",
             structure: citm_catalog::CitmCatalog,
             $($args)*
@@ -33,6 +230,7 @@ macro_rules! bench {
 
         #[cfg(feature = "file-twitter")]
         $bench! {
+            library: $name,
             path: "data/twitter.json",
             structure: twitter::Twitter,
             $($args)*
@@ -42,6 +240,7 @@ macro_rules! bench {
 
 macro_rules! bench_file {
     {
+        library: $library:expr,
         path: $path:expr,
         structure: $structure:ty,
         dom: $dom:ty,
@@ -51,11 +250,13 @@ macro_rules! bench_file {
             parse_struct: $parse_struct:expr,
             stringify_struct: $stringify_struct:expr,
         )*
+        $(
+            sax_count: $sax_count:expr,
+        )*
     } => {
         let num_trials = num_trials().unwrap_or(256);
 
-        print!("{:22}", $path);
-        io::stdout().flush().unwrap();
+        tprint!("{:22}", $path);
 
         let contents = {
             let mut vec = Vec::new();
@@ -65,59 +266,167 @@ macro_rules! bench_file {
 
         #[cfg(feature = "parse-dom")]
         {
-            let dur = timer::bench(num_trials, || {
+            let dur = bench_timer::bench(num_trials, || {
                 let parsed: $dom = $parse_dom(&contents).unwrap();
                 parsed
             });
-            print!("{:6} MB/s", throughput(dur, contents.len()));
-            io::stdout().flush().unwrap();
+            record($library, $path, "dom-parse", throughput(dur, contents.len()));
         }
         #[cfg(not(feature = "parse-dom"))]
-        print!("          ");
+        tprint!("          ");
+
+        #[cfg(all(feature = "parse-dom", feature = "alloc-stats"))]
+        {
+            let (_parsed, allocated) = measure_peak_allocated(|| {
+                let parsed: $dom = $parse_dom(&contents).unwrap();
+                parsed
+            });
+            record_alloc($library, $path, "dom-parse-peak-alloc", allocated as f64 / contents.len() as f64);
+        }
+
+        #[cfg(all(feature = "parse-dom", feature = "stats"))]
+        {
+            let stats = bench_timer::bench_stats(num_trials, || {
+                let parsed: $dom = $parse_dom(&contents).unwrap();
+                parsed
+            });
+            record($library, $path, "dom-parse-median", throughput(stats.median, contents.len()));
+            record($library, $path, "dom-parse-p99", throughput(stats.p99, contents.len()));
+            report(
+                $library,
+                $path,
+                "dom-parse-stddev",
+                "ms",
+                stats.stddev.as_secs_f64() * 1000.0,
+            );
+        }
+
+        $(
+            #[cfg(feature = "parse-sax")]
+            {
+                let dur = bench_timer::bench(num_trials, || $sax_count(&contents));
+                record($library, $path, "sax-parse", throughput(dur, contents.len()));
+            }
+        )*
+        #[cfg(not(feature = "parse-sax"))]
+        tprint!("          ");
 
         #[cfg(feature = "stringify-dom")]
         {
             let len = contents.len();
             let dom: $dom = $parse_dom(&contents).unwrap();
-            let dur = timer::bench_with_buf(num_trials, len, |out| {
+            let dur = bench_timer::bench_with_buf(num_trials, len, |out| {
                 $stringify_dom(out, &dom).unwrap()
             });
             let mut serialized = Vec::new();
             $stringify_dom(&mut serialized, &dom).unwrap();
-            print!("{:6} MB/s", throughput(dur, serialized.len()));
-            io::stdout().flush().unwrap();
+            record($library, $path, "dom-stringify", throughput(dur, serialized.len()));
         }
         #[cfg(not(feature = "stringify-dom"))]
-        print!("          ");
+        tprint!("          ");
+
+        #[cfg(all(feature = "stringify-dom", feature = "stats"))]
+        {
+            let len = contents.len();
+            let dom: $dom = $parse_dom(&contents).unwrap();
+            let stats = bench_timer::bench_stats_with_buf(num_trials, len, |out| {
+                $stringify_dom(out, &dom).unwrap()
+            });
+            let mut serialized = Vec::new();
+            $stringify_dom(&mut serialized, &dom).unwrap();
+            record($library, $path, "dom-stringify-median", throughput(stats.median, serialized.len()));
+            record($library, $path, "dom-stringify-p99", throughput(stats.p99, serialized.len()));
+            report(
+                $library,
+                $path,
+                "dom-stringify-stddev",
+                "ms",
+                stats.stddev.as_secs_f64() * 1000.0,
+            );
+        }
 
         $(
             #[cfg(feature = "parse-struct")]
             {
-                let dur = timer::bench(num_trials, || {
+                let dur = bench_timer::bench(num_trials, || {
                     let parsed: $structure = $parse_struct(&contents).unwrap();
                     parsed
                 });
-                print!("{:6} MB/s", throughput(dur, contents.len()));
-                io::stdout().flush().unwrap();
+                record($library, $path, "struct-parse", throughput(dur, contents.len()));
             }
             #[cfg(not(feature = "parse-struct"))]
-            print!("          ");
+            tprint!("          ");
+
+            #[cfg(all(feature = "parse-struct", feature = "alloc-stats"))]
+            {
+                let (_parsed, allocated) = measure_peak_allocated(|| {
+                    let parsed: $structure = $parse_struct(&contents).unwrap();
+                    parsed
+                });
+                record_alloc($library, $path, "struct-parse-peak-alloc", allocated as f64 / contents.len() as f64);
+            }
+
+            #[cfg(all(feature = "parse-struct", feature = "stats"))]
+            {
+                let stats = bench_timer::bench_stats(num_trials, || {
+                    let parsed: $structure = $parse_struct(&contents).unwrap();
+                    parsed
+                });
+                record($library, $path, "struct-parse-median", throughput(stats.median, contents.len()));
+                record($library, $path, "struct-parse-p99", throughput(stats.p99, contents.len()));
+                report(
+                    $library,
+                    $path,
+                    "struct-parse-stddev",
+                    "ms",
+                    stats.stddev.as_secs_f64() * 1000.0,
+                );
+            }
 
             #[cfg(feature = "stringify-struct")]
             {
                 let len = contents.len();
                 let parsed: $structure = $parse_struct(&contents).unwrap();
-                let dur = timer::bench_with_buf(num_trials, len, |out| {
+                let dur = bench_timer::bench_with_buf(num_trials, len, |out| {
+                    $stringify_struct(out, &parsed).unwrap()
+                });
+                let mut serialized = Vec::new();
+                $stringify_dom(&mut serialized, &parsed).unwrap();
+                record($library, $path, "struct-stringify", throughput(dur, serialized.len()));
+            }
+
+            #[cfg(all(feature = "stringify-struct", feature = "stats"))]
+            {
+                let len = contents.len();
+                let parsed: $structure = $parse_struct(&contents).unwrap();
+                let stats = bench_timer::bench_stats_with_buf(num_trials, len, |out| {
                     $stringify_struct(out, &parsed).unwrap()
                 });
                 let mut serialized = Vec::new();
                 $stringify_dom(&mut serialized, &parsed).unwrap();
-                print!("{:6} MB/s", throughput(dur, serialized.len()));
-                io::stdout().flush().unwrap();
+                record(
+                    $library,
+                    $path,
+                    "struct-stringify-median",
+                    throughput(stats.median, serialized.len()),
+                );
+                record(
+                    $library,
+                    $path,
+                    "struct-stringify-p99",
+                    throughput(stats.p99, serialized.len()),
+                );
+                report(
+                    $library,
+                    $path,
+                    "struct-stringify-stddev",
+                    "ms",
+                    stats.stddev.as_secs_f64() * 1000.0,
+                );
             }
         )*
 
-        println!();
+        tprintln!();
     }
 }
 
@@ -128,13 +437,13 @@ macro_rules! bench_file {
 #[cfg(feature = "lib-simd-json")]
 macro_rules! bench_file_simd_json {
     {
+        library: $library:expr,
         path: $path:expr,
         structure: $structure:ty,
     } => {
         let num_trials = num_trials().unwrap_or(256);
 
-        print!("{:22}", $path);
-        io::stdout().flush().unwrap();
+        tprint!("{:22}", $path);
 
         let contents = {
             let mut vec = Vec::new();
@@ -144,7 +453,7 @@ macro_rules! bench_file_simd_json {
 
         #[cfg(feature = "parse-dom")]
         {
-            use timer::Benchmark;
+            use bench_timer::Benchmark;
             let mut benchmark = Benchmark::new();
             let mut data = contents.clone();
             for _ in 0..num_trials {
@@ -154,31 +463,98 @@ macro_rules! bench_file_simd_json {
                 timer.stop();
             }
             let dur = benchmark.min_elapsed();
-            print!("{:6} MB/s", throughput(dur, contents.len()));
-            io::stdout().flush().unwrap();
+            record($library, $path, "dom-parse", throughput(dur, contents.len()));
         }
         #[cfg(not(feature = "parse-dom"))]
-        print!("          ");
+        tprint!("          ");
+
+        #[cfg(all(feature = "parse-dom", feature = "alloc-stats"))]
+        {
+            let mut data = contents.clone();
+            data.as_mut_slice().clone_from_slice(contents.as_slice());
+            let (_parsed, allocated) = measure_peak_allocated(|| simd_json_parse_dom(&mut data).unwrap());
+            record_alloc($library, $path, "dom-parse-peak-alloc", allocated as f64 / contents.len() as f64);
+        }
+
+        #[cfg(all(feature = "parse-dom", feature = "stats"))]
+        {
+            use bench_timer::Benchmark;
+            let mut benchmark = Benchmark::new();
+            let mut data = contents.clone();
+            for _ in 0..num_trials {
+                data.as_mut_slice().clone_from_slice(contents.as_slice());
+                let mut timer = benchmark.start();
+                let _parsed = simd_json_parse_dom(&mut data).unwrap();
+                timer.stop();
+            }
+            let stats = benchmark.stats();
+            record($library, $path, "dom-parse-median", throughput(stats.median, contents.len()));
+            record($library, $path, "dom-parse-p99", throughput(stats.p99, contents.len()));
+            report(
+                $library,
+                $path,
+                "dom-parse-stddev",
+                "ms",
+                stats.stddev.as_secs_f64() * 1000.0,
+            );
+        }
+
+        #[cfg(feature = "parse-sax")]
+        {
+            use bench_timer::Benchmark;
+            let mut benchmark = Benchmark::new();
+            let mut data = contents.clone();
+            for _ in 0..num_trials {
+                data.as_mut_slice().clone_from_slice(contents.as_slice());
+                let mut timer = benchmark.start();
+                let _count = simd_json_sax_count(&mut data);
+                timer.stop();
+            }
+            let dur = benchmark.min_elapsed();
+            record($library, $path, "sax-parse", throughput(dur, contents.len()));
+        }
+        #[cfg(not(feature = "parse-sax"))]
+        tprint!("          ");
 
         #[cfg(feature = "stringify-dom")]
         {
             let len = contents.len();
             let mut data = contents.clone();
             let dom = simd_json_parse_dom(&mut data).unwrap();
-            let dur = timer::bench_with_buf(num_trials, len, |out| {
+            let dur = bench_timer::bench_with_buf(num_trials, len, |out| {
                 simd_json::Writable::write(&dom, out).unwrap()
             });
             let mut serialized = Vec::new();
             simd_json::Writable::write(&dom, &mut serialized).unwrap();
-            print!("{:6} MB/s", throughput(dur, serialized.len()));
-            io::stdout().flush().unwrap();
+            record($library, $path, "dom-stringify", throughput(dur, serialized.len()));
         }
         #[cfg(not(feature = "stringify-dom"))]
-        print!("          ");
+        tprint!("          ");
+
+        #[cfg(all(feature = "stringify-dom", feature = "stats"))]
+        {
+            let len = contents.len();
+            let mut data = contents.clone();
+            let dom = simd_json_parse_dom(&mut data).unwrap();
+            let stats = bench_timer::bench_stats_with_buf(num_trials, len, |out| {
+                simd_json::Writable::write(&dom, out).unwrap()
+            });
+            let mut serialized = Vec::new();
+            simd_json::Writable::write(&dom, &mut serialized).unwrap();
+            record($library, $path, "dom-stringify-median", throughput(stats.median, serialized.len()));
+            record($library, $path, "dom-stringify-p99", throughput(stats.p99, serialized.len()));
+            report(
+                $library,
+                $path,
+                "dom-stringify-stddev",
+                "ms",
+                stats.stddev.as_secs_f64() * 1000.0,
+            );
+        }
 
         #[cfg(feature = "parse-struct")]
         {
-            use timer::Benchmark;
+            use bench_timer::Benchmark;
             let mut benchmark = Benchmark::new();
             let mut data = contents.clone();
             for _ in 0..num_trials {
@@ -188,24 +564,55 @@ macro_rules! bench_file_simd_json {
                 timer.stop();
             }
             let dur = benchmark.min_elapsed();
-            print!("{:6} MB/s", throughput(dur, contents.len()));
-            io::stdout().flush().unwrap();
+            record($library, $path, "struct-parse", throughput(dur, contents.len()));
         }
 
-        println!();
+        #[cfg(all(feature = "parse-struct", feature = "alloc-stats"))]
+        {
+            let mut data = contents.clone();
+            data.as_mut_slice().clone_from_slice(contents.as_slice());
+            let (_parsed, allocated) =
+                measure_peak_allocated(|| -> $structure { simd_json_parse_struct(&mut data).unwrap() });
+            record_alloc($library, $path, "struct-parse-peak-alloc", allocated as f64 / contents.len() as f64);
+        }
+
+        #[cfg(all(feature = "parse-struct", feature = "stats"))]
+        {
+            use bench_timer::Benchmark;
+            let mut benchmark = Benchmark::new();
+            let mut data = contents.clone();
+            for _ in 0..num_trials {
+                data.as_mut_slice().clone_from_slice(contents.as_slice());
+                let mut timer = benchmark.start();
+                let _parsed: $structure = simd_json_parse_struct(&mut data).unwrap();
+                timer.stop();
+            }
+            let stats = benchmark.stats();
+            record($library, $path, "struct-parse-median", throughput(stats.median, contents.len()));
+            record($library, $path, "struct-parse-p99", throughput(stats.p99, contents.len()));
+            report(
+                $library,
+                $path,
+                "struct-parse-stddev",
+                "ms",
+                stats.stddev.as_secs_f64() * 1000.0,
+            );
+        }
+
+        tprintln!();
     }
 }
 
 #[cfg(feature = "lib-rmp")]
 macro_rules! bench_file_msgpack {
     {
+        library: $library:expr,
         path: $path:expr,
         structure: $structure:ty,
     } => {
         let num_trials = num_trials().unwrap_or(256);
 
-        print!("{:22}", $path);
-        io::stdout().flush().unwrap();
+        tprint!("{:22}", $path);
 
         let contents: Vec<u8> = {
             let structure: $structure = serde_json::from_reader(File::open($path).unwrap()).unwrap();
@@ -214,7 +621,7 @@ macro_rules! bench_file_msgpack {
 
         #[cfg(feature = "parse-dom")]
         {
-            use timer::Benchmark;
+            use bench_timer::Benchmark;
             let mut benchmark = Benchmark::new();
             for _ in 0..num_trials {
                 let mut timer = benchmark.start();
@@ -222,58 +629,408 @@ macro_rules! bench_file_msgpack {
                 timer.stop();
             }
             let dur = benchmark.min_elapsed();
-            print!("{:6} MB/s", throughput(dur, contents.len()));
-            io::stdout().flush().unwrap();
+            record($library, $path, "dom-parse", throughput(dur, contents.len()));
         }
         #[cfg(not(feature = "parse-dom"))]
-        print!("          ");
+        tprint!("          ");
+
+        #[cfg(all(feature = "parse-dom", feature = "stats"))]
+        {
+            let stats = bench_timer::bench_stats(num_trials, || {
+                rmpv::decode::value::read_value(&mut contents.as_slice()).unwrap()
+            });
+            record($library, $path, "dom-parse-median", throughput(stats.median, contents.len()));
+            record($library, $path, "dom-parse-p99", throughput(stats.p99, contents.len()));
+            report(
+                $library,
+                $path,
+                "dom-parse-stddev",
+                "ms",
+                stats.stddev.as_secs_f64() * 1000.0,
+            );
+        }
 
         #[cfg(feature = "stringify-dom")]
         {
             let len = contents.len();
             let dom = rmpv::decode::value::read_value(&mut contents.as_slice()).unwrap();
-            let dur = timer::bench_with_buf(num_trials, len, |out| {
+            let dur = bench_timer::bench_with_buf(num_trials, len, |out| {
                 rmpv::encode::write_value(out, &dom)
             });
             let mut serialized = Vec::new();
             rmpv::encode::write_value(&mut serialized, &dom).unwrap();
-            print!("{:6} MB/s", throughput(dur, serialized.len()));
+            record($library, $path, "dom-stringify", throughput(dur, serialized.len()));
         }
         #[cfg(not(feature = "stringify-dom"))]
-        print!("          ");
+        tprint!("          ");
 
+        #[cfg(all(feature = "stringify-dom", feature = "stats"))]
+        {
+            let len = contents.len();
+            let dom = rmpv::decode::value::read_value(&mut contents.as_slice()).unwrap();
+            let stats = bench_timer::bench_stats_with_buf(num_trials, len, |out| {
+                rmpv::encode::write_value(out, &dom).unwrap()
+            });
+            let mut serialized = Vec::new();
+            rmpv::encode::write_value(&mut serialized, &dom).unwrap();
+            record($library, $path, "dom-stringify-median", throughput(stats.median, serialized.len()));
+            record($library, $path, "dom-stringify-p99", throughput(stats.p99, serialized.len()));
+            report(
+                $library,
+                $path,
+                "dom-stringify-stddev",
+                "ms",
+                stats.stddev.as_secs_f64() * 1000.0,
+            );
+        }
 
         #[cfg(feature = "parse-struct")]
         {
-            let dur = timer::bench(num_trials, || {
+            let dur = bench_timer::bench(num_trials, || {
                 let parsed: $structure = rmp_serde::from_slice(&contents).unwrap();
                 parsed
             });
-            print!("{:6} MB/s", throughput(dur, contents.len()));
-            io::stdout().flush().unwrap();
+            record($library, $path, "struct-parse", throughput(dur, contents.len()));
         }
         #[cfg(not(feature = "parse-struct"))]
-        print!("          ");
+        tprint!("          ");
+
+        #[cfg(all(feature = "parse-struct", feature = "stats"))]
+        {
+            let stats = bench_timer::bench_stats(num_trials, || {
+                let parsed: $structure = rmp_serde::from_slice(&contents).unwrap();
+                parsed
+            });
+            record($library, $path, "struct-parse-median", throughput(stats.median, contents.len()));
+            record($library, $path, "struct-parse-p99", throughput(stats.p99, contents.len()));
+            report(
+                $library,
+                $path,
+                "struct-parse-stddev",
+                "ms",
+                stats.stddev.as_secs_f64() * 1000.0,
+            );
+        }
 
         #[cfg(feature = "stringify-struct")]
         {
             let len = contents.len();
             let parsed: $structure = rmp_serde::from_slice(&contents).unwrap();
-            let dur = timer::bench_with_buf(num_trials, len, |out| {
+            let dur = bench_timer::bench_with_buf(num_trials, len, |out| {
                 rmp_serde::encode::write(out, &parsed).unwrap()
             });
             let mut serialized = Vec::new();
             rmp_serde::encode::write(&mut serialized, &parsed).unwrap();
-            print!("{:6} MB/s", throughput(dur, serialized.len()));
-            io::stdout().flush().unwrap();
+            record($library, $path, "struct-stringify", throughput(dur, serialized.len()));
         }
 
-        println!();
+        #[cfg(all(feature = "stringify-struct", feature = "stats"))]
+        {
+            let len = contents.len();
+            let parsed: $structure = rmp_serde::from_slice(&contents).unwrap();
+            let stats = bench_timer::bench_stats_with_buf(num_trials, len, |out| {
+                rmp_serde::encode::write(out, &parsed).unwrap()
+            });
+            let mut serialized = Vec::new();
+            rmp_serde::encode::write(&mut serialized, &parsed).unwrap();
+            record(
+                $library,
+                $path,
+                "struct-stringify-median",
+                throughput(stats.median, serialized.len()),
+            );
+            record(
+                $library,
+                $path,
+                "struct-stringify-p99",
+                throughput(stats.p99, serialized.len()),
+            );
+            report(
+                $library,
+                $path,
+                "struct-stringify-stddev",
+                "ms",
+                stats.stddev.as_secs_f64() * 1000.0,
+            );
+        }
+
+        tprintln!();
+    };
+}
+
+#[cfg(feature = "lib-cbor")]
+macro_rules! bench_file_cbor {
+    {
+        library: $library:expr,
+        path: $path:expr,
+        structure: $structure:ty,
+    } => {
+        let num_trials = num_trials().unwrap_or(256);
+
+        tprint!("{:22}", $path);
+
+        let contents: Vec<u8> = {
+            let structure: $structure = serde_json::from_reader(File::open($path).unwrap()).unwrap();
+            serde_cbor::to_vec(&structure).unwrap()
+        };
+
+        #[cfg(feature = "parse-dom")]
+        {
+            let dur = bench_timer::bench(num_trials, || {
+                let parsed: serde_cbor::Value = serde_cbor::from_slice(&contents).unwrap();
+                parsed
+            });
+            record($library, $path, "dom-parse", throughput(dur, contents.len()));
+        }
+        #[cfg(not(feature = "parse-dom"))]
+        tprint!("          ");
+
+        #[cfg(all(feature = "parse-dom", feature = "stats"))]
+        {
+            let stats = bench_timer::bench_stats(num_trials, || {
+                let parsed: serde_cbor::Value = serde_cbor::from_slice(&contents).unwrap();
+                parsed
+            });
+            record($library, $path, "dom-parse-median", throughput(stats.median, contents.len()));
+            record($library, $path, "dom-parse-p99", throughput(stats.p99, contents.len()));
+            report(
+                $library,
+                $path,
+                "dom-parse-stddev",
+                "ms",
+                stats.stddev.as_secs_f64() * 1000.0,
+            );
+        }
+
+        #[cfg(feature = "stringify-dom")]
+        {
+            let len = contents.len();
+            let dom: serde_cbor::Value = serde_cbor::from_slice(&contents).unwrap();
+            let dur = bench_timer::bench_with_buf(num_trials, len, |out| {
+                serde_cbor::to_writer(out, &dom).unwrap()
+            });
+            let mut serialized = Vec::new();
+            serde_cbor::to_writer(&mut serialized, &dom).unwrap();
+            record($library, $path, "dom-stringify", throughput(dur, serialized.len()));
+        }
+        #[cfg(not(feature = "stringify-dom"))]
+        tprint!("          ");
+
+        #[cfg(all(feature = "stringify-dom", feature = "stats"))]
+        {
+            let len = contents.len();
+            let dom: serde_cbor::Value = serde_cbor::from_slice(&contents).unwrap();
+            let stats = bench_timer::bench_stats_with_buf(num_trials, len, |out| {
+                serde_cbor::to_writer(out, &dom).unwrap()
+            });
+            let mut serialized = Vec::new();
+            serde_cbor::to_writer(&mut serialized, &dom).unwrap();
+            record($library, $path, "dom-stringify-median", throughput(stats.median, serialized.len()));
+            record($library, $path, "dom-stringify-p99", throughput(stats.p99, serialized.len()));
+            report(
+                $library,
+                $path,
+                "dom-stringify-stddev",
+                "ms",
+                stats.stddev.as_secs_f64() * 1000.0,
+            );
+        }
+
+        #[cfg(feature = "parse-struct")]
+        {
+            let dur = bench_timer::bench(num_trials, || {
+                let parsed: $structure = serde_cbor::from_slice(&contents).unwrap();
+                parsed
+            });
+            record($library, $path, "struct-parse", throughput(dur, contents.len()));
+        }
+        #[cfg(not(feature = "parse-struct"))]
+        tprint!("          ");
+
+        #[cfg(all(feature = "parse-struct", feature = "stats"))]
+        {
+            let stats = bench_timer::bench_stats(num_trials, || {
+                let parsed: $structure = serde_cbor::from_slice(&contents).unwrap();
+                parsed
+            });
+            record($library, $path, "struct-parse-median", throughput(stats.median, contents.len()));
+            record($library, $path, "struct-parse-p99", throughput(stats.p99, contents.len()));
+            report(
+                $library,
+                $path,
+                "struct-parse-stddev",
+                "ms",
+                stats.stddev.as_secs_f64() * 1000.0,
+            );
+        }
+
+        #[cfg(feature = "stringify-struct")]
+        {
+            let len = contents.len();
+            let parsed: $structure = serde_cbor::from_slice(&contents).unwrap();
+            let dur = bench_timer::bench_with_buf(num_trials, len, |out| {
+                serde_cbor::to_writer(out, &parsed).unwrap()
+            });
+            let mut serialized = Vec::new();
+            serde_cbor::to_writer(&mut serialized, &parsed).unwrap();
+            record($library, $path, "struct-stringify", throughput(dur, serialized.len()));
+        }
+
+        #[cfg(all(feature = "stringify-struct", feature = "stats"))]
+        {
+            let len = contents.len();
+            let parsed: $structure = serde_cbor::from_slice(&contents).unwrap();
+            let stats = bench_timer::bench_stats_with_buf(num_trials, len, |out| {
+                serde_cbor::to_writer(out, &parsed).unwrap()
+            });
+            let mut serialized = Vec::new();
+            serde_cbor::to_writer(&mut serialized, &parsed).unwrap();
+            record(
+                $library,
+                $path,
+                "struct-stringify-median",
+                throughput(stats.median, serialized.len()),
+            );
+            record(
+                $library,
+                $path,
+                "struct-stringify-p99",
+                throughput(stats.p99, serialized.len()),
+            );
+            report(
+                $library,
+                $path,
+                "struct-stringify-stddev",
+                "ms",
+                stats.stddev.as_secs_f64() * 1000.0,
+            );
+        }
+
+        tprintln!();
+    };
+}
+
+#[cfg(feature = "lib-baseline")]
+macro_rules! bench_file_baseline {
+    {
+        library: $library:expr,
+        path: $path:expr,
+        structure: $structure:ty,
+        parse: $parse:expr,
+        stringify_noescape: $stringify_noescape:expr,
+        stringify_escaped: $stringify_escaped:expr,
+    } => {
+        let num_trials = num_trials().unwrap_or(256);
+
+        tprint!("{:22}", $path);
+
+        let contents = {
+            let mut vec = Vec::new();
+            File::open($path).unwrap().read_to_end(&mut vec).unwrap();
+            vec
+        };
+
+        {
+            let dur = bench_timer::bench(num_trials, || -> $structure { $parse(&contents) });
+            record($library, $path, "parse", throughput(dur, contents.len()));
+        }
+
+        #[cfg(feature = "stats")]
+        {
+            let stats = bench_timer::bench_stats(num_trials, || -> $structure { $parse(&contents) });
+            record($library, $path, "parse-median", throughput(stats.median, contents.len()));
+            record($library, $path, "parse-p99", throughput(stats.p99, contents.len()));
+            report($library, $path, "parse-stddev", "ms", stats.stddev.as_secs_f64() * 1000.0);
+        }
+
+        {
+            let len = contents.len();
+            let parsed: $structure = $parse(&contents);
+            let dur = bench_timer::bench_with_buf(num_trials, len, |out| {
+                $stringify_noescape(out, &parsed).unwrap()
+            });
+            let mut serialized = Vec::new();
+            $stringify_noescape(&mut serialized, &parsed).unwrap();
+            record($library, $path, "stringify-noescape", throughput(dur, serialized.len()));
+        }
+
+        #[cfg(feature = "stats")]
+        {
+            let len = contents.len();
+            let parsed: $structure = $parse(&contents);
+            let stats = bench_timer::bench_stats_with_buf(num_trials, len, |out| {
+                $stringify_noescape(out, &parsed).unwrap()
+            });
+            let mut serialized = Vec::new();
+            $stringify_noescape(&mut serialized, &parsed).unwrap();
+            record(
+                $library,
+                $path,
+                "stringify-noescape-median",
+                throughput(stats.median, serialized.len()),
+            );
+            record(
+                $library,
+                $path,
+                "stringify-noescape-p99",
+                throughput(stats.p99, serialized.len()),
+            );
+            report(
+                $library,
+                $path,
+                "stringify-noescape-stddev",
+                "ms",
+                stats.stddev.as_secs_f64() * 1000.0,
+            );
+        }
+
+        {
+            let len = contents.len();
+            let parsed: $structure = $parse(&contents);
+            let dur = bench_timer::bench_with_buf(num_trials, len, |out| {
+                $stringify_escaped(out, &parsed).unwrap()
+            });
+            let mut serialized = Vec::new();
+            $stringify_escaped(&mut serialized, &parsed).unwrap();
+            record($library, $path, "stringify-escaped", throughput(dur, serialized.len()));
+        }
+
+        #[cfg(feature = "stats")]
+        {
+            let len = contents.len();
+            let parsed: $structure = $parse(&contents);
+            let stats = bench_timer::bench_stats_with_buf(num_trials, len, |out| {
+                $stringify_escaped(out, &parsed).unwrap()
+            });
+            let mut serialized = Vec::new();
+            $stringify_escaped(&mut serialized, &parsed).unwrap();
+            record(
+                $library,
+                $path,
+                "stringify-escaped-median",
+                throughput(stats.median, serialized.len()),
+            );
+            record(
+                $library,
+                $path,
+                "stringify-escaped-p99",
+                throughput(stats.p99, serialized.len()),
+            );
+            report(
+                $library,
+                $path,
+                "stringify-escaped-stddev",
+                "ms",
+                stats.stddev.as_secs_f64() * 1000.0,
+            );
+        }
+
+        tprintln!();
     };
 }
 
 fn main() {
-    print!("{:>35}{:>24}", "DOM", "STRUCT");
+    tprint!("{:>35}{:>24}", "DOM", "STRUCT");
 
     #[cfg(feature = "lib-serde")]
     bench! {
@@ -284,6 +1041,7 @@ fn main() {
         stringify_dom: serde_json::to_writer,
         parse_struct: serde_json_parse_struct,
         stringify_struct: serde_json::to_writer,
+        sax_count: serde_json_sax_count,
     }
 
     #[cfg(feature = "lib-rustc-serialize")]
@@ -297,6 +1055,15 @@ fn main() {
         stringify_struct: rustc_serialize_stringify,
     }
 
+    #[cfg(feature = "lib-json")]
+    bench! {
+        name: "json",
+        bench: bench_file,
+        dom: json::JsonValue,
+        parse_dom: json_parse_dom,
+        stringify_dom: json_stringify,
+    }
+
     #[cfg(feature = "lib-simd-json")]
     bench! {
         name: "simd-json",
@@ -308,6 +1075,53 @@ fn main() {
         name: "rmp",
         bench: bench_file_msgpack,
     }
+
+    #[cfg(feature = "lib-cbor")]
+    bench! {
+        name: "cbor",
+        bench: bench_file_cbor,
+    }
+
+    // Hand-written codecs, specialized per corpus, so they can't be driven
+    // through the generic `bench!` dispatch above: it assumes one set of
+    // function names works for every `$structure`.
+    #[cfg(feature = "lib-baseline")]
+    {
+        let name = format!(" {} ", "baseline");
+        tprintln!("\n{:=^26} parse|stringify no-esc|stringify esc ====", name);
+
+        #[cfg(feature = "file-canada")]
+        bench_file_baseline! {
+            library: "baseline",
+            path: "data/canada.json",
+            structure: canada::Canada,
+            parse: baseline::parse_canada,
+            stringify_noescape: baseline::stringify_canada_noescape,
+            stringify_escaped: baseline::stringify_canada_escaped,
+        }
+
+        #[cfg(feature = "file-citm-catalog")]
+        bench_file_baseline! {
+            library: "baseline",
+            path: "data/citm_catalog.json",
+            structure: citm_catalog::CitmCatalog,
+            parse: baseline::parse_citm_catalog,
+            stringify_noescape: baseline::stringify_citm_catalog_noescape,
+            stringify_escaped: baseline::stringify_citm_catalog_escaped,
+        }
+
+        #[cfg(feature = "file-twitter")]
+        bench_file_baseline! {
+            library: "baseline",
+            path: "data/twitter.json",
+            structure: twitter::Twitter,
+            parse: baseline::parse_twitter,
+            stringify_noescape: baseline::stringify_twitter_noescape,
+            stringify_escaped: baseline::stringify_twitter_escaped,
+        }
+    }
+
+    print_results();
 }
 
 #[cfg(all(
@@ -320,6 +1134,93 @@ fn serde_json_parse_dom(bytes: &[u8]) -> serde_json::Result<serde_json::Value> {
     serde_json::from_str(s)
 }
 
+// Counts events the way a SAX-style parser would, without ever materializing
+// a DOM or a struct: every scalar, object/array start, and map key bumps the
+// counter and is immediately dropped. Works for any format with a serde
+// `Deserializer`, so it's shared between serde_json and simd-json below.
+#[cfg(feature = "parse-sax")]
+struct CountEvents(u64);
+
+#[cfg(feature = "parse-sax")]
+impl<'de> serde::de::Visitor<'de> for &mut CountEvents {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("any valid JSON value")
+    }
+
+    fn visit_bool<E>(self, _value: bool) -> Result<(), E> {
+        self.0 += 1;
+        Ok(())
+    }
+
+    fn visit_i64<E>(self, _value: i64) -> Result<(), E> {
+        self.0 += 1;
+        Ok(())
+    }
+
+    fn visit_u64<E>(self, _value: u64) -> Result<(), E> {
+        self.0 += 1;
+        Ok(())
+    }
+
+    fn visit_f64<E>(self, _value: f64) -> Result<(), E> {
+        self.0 += 1;
+        Ok(())
+    }
+
+    fn visit_str<E>(self, _value: &str) -> Result<(), E> {
+        self.0 += 1;
+        Ok(())
+    }
+
+    fn visit_unit<E>(self) -> Result<(), E> {
+        self.0 += 1;
+        Ok(())
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<(), A::Error>
+    where
+        A: serde::de::SeqAccess<'de>,
+    {
+        self.0 += 1;
+        while seq.next_element_seed(&mut *self)?.is_some() {}
+        Ok(())
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<(), A::Error>
+    where
+        A: serde::de::MapAccess<'de>,
+    {
+        self.0 += 1;
+        while map.next_key_seed(&mut *self)?.is_some() {
+            map.next_value_seed(&mut *self)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "parse-sax")]
+impl<'de> serde::de::DeserializeSeed<'de> for &mut CountEvents {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<(), D::Error>
+    where
+        D: serde::de::Deserializer<'de>,
+    {
+        deserializer.deserialize_any(self)
+    }
+}
+
+#[cfg(all(feature = "lib-serde", feature = "parse-sax"))]
+fn serde_json_sax_count(bytes: &[u8]) -> u64 {
+    use serde::de::DeserializeSeed;
+    let mut counter = CountEvents(0);
+    let mut de = serde_json::Deserializer::from_slice(bytes);
+    (&mut counter).deserialize(&mut de).unwrap();
+    counter.0
+}
+
 #[cfg(all(
     feature = "lib-serde",
     any(feature = "parse-struct", feature = "stringify-struct")
@@ -370,6 +1271,21 @@ where
     value.encode(&mut encoder)
 }
 
+#[cfg(all(
+    feature = "lib-json",
+    any(feature = "parse-dom", feature = "stringify-dom")
+))]
+fn json_parse_dom(bytes: &[u8]) -> Result<json::JsonValue, json::Error> {
+    use std::str;
+    let s = str::from_utf8(bytes).unwrap();
+    json::parse(s)
+}
+
+#[cfg(all(feature = "lib-json", feature = "stringify-dom"))]
+fn json_stringify<W: Write>(mut writer: W, value: &json::JsonValue) -> io::Result<()> {
+    write!(writer, "{}", value)
+}
+
 #[cfg(all(
     feature = "lib-simd-json",
     any(feature = "parse-dom", feature = "stringify-dom")
@@ -378,6 +1294,15 @@ fn simd_json_parse_dom(bytes: &mut [u8]) -> simd_json::Result<simd_json::Borrowe
     simd_json::to_borrowed_value(bytes)
 }
 
+#[cfg(all(feature = "lib-simd-json", feature = "parse-sax"))]
+fn simd_json_sax_count(bytes: &mut [u8]) -> u64 {
+    use serde::de::DeserializeSeed;
+    let mut counter = CountEvents(0);
+    let mut de = simd_json::Deserializer::from_slice(bytes).unwrap();
+    (&mut counter).deserialize(&mut de).unwrap();
+    counter.0
+}
+
 #[cfg(all(
     feature = "lib-simd-json",
     any(feature = "parse-struct", feature = "stringify-struct")